@@ -0,0 +1,61 @@
+// Path parsing extension for disambiguating which co-linked crate
+// version an expression path refers to.
+//
+// `use crate_name(vers = "...")` already annotates which version a
+// *name binding* pulls in. The same `(vers = "...")` annotation is now
+// also accepted directly after a crate-name path segment in an
+// expression, so a single call site can pick a specific version when
+// more than one co-linked version exports the symbol being called --
+// see resolve::resolve_crate_symbol and
+// src/test/run-pass/crateresolve-ambiguity-disambiguate.rs:
+//
+//     crateresolve7(vers = "0.1")::h()
+//
+// This parses to the same path AST as a plain `crateresolve7::h()`
+// call, with `vers` set on the leading segment instead of none; the
+// resolver reads that field back out in resolve_crate_symbol.
+
+type path_segment = { ident: str, vers: option<str> };
+
+// Called from parse_path for each segment. The lookahead below is
+// what tells `crate_name(vers = "...")::f` apart from an ordinary
+// call `f(x)`: only the former has its `(...)` consumed here.
+fn parse_path_segment(p: parser) -> path_segment {
+    let ident = parse_ident(p);
+
+    // A `(` only starts a vers-clause when the next five tokens are
+    // exactly `( vers = <str> )` *and* a `::` follows immediately
+    // after -- i.e. when this can only be a crate-version-qualified
+    // path prefix, never the start of an ordinary call's argument
+    // list. Lookahead is read-only (p.nth does not consume), so if the
+    // shape doesn't match, nothing has been bumped and the identifier
+    // is returned as a plain, unqualified segment.
+    let vers = if p.nth(0u) == token::LPAREN &&
+                  p.nth(1u) == token::ident("vers") &&
+                  p.nth(2u) == token::EQ &&
+                  p.nth_is_str_lit(3u) &&
+                  p.nth(4u) == token::RPAREN &&
+                  p.nth(5u) == token::COLONCOLON {
+        p.bump();  // (
+        p.bump();  // vers
+        p.bump();  // =
+        let v = parse_str_lit(p);
+        p.bump();  // )
+        some(v)
+    } else {
+        none
+    };
+    ret { ident: ident, vers: vers };
+}
+
+// Parses a `::`-separated path whose segments may carry a
+// `(vers = "...")` qualifier (resolve reads it only from the leading
+// crate-name segment). An identifier followed by an ordinary argument
+// list comes back as a one-segment path with its `(` unconsumed.
+fn parse_path(p: parser) -> [path_segment] {
+    let mutable segs = [parse_path_segment(p)];
+    while p.eat(token::COLONCOLON) {
+        segs += [parse_path_segment(p)];
+    }
+    ret segs;
+}
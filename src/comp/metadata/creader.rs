@@ -0,0 +1,111 @@
+// Reads crate metadata off the search path and selects which crate
+// file satisfies a `use name(vers = "...")` clause.
+//
+// Before this, `vers` was compared to a candidate's own `vers`
+// attribute as raw strings, so two differently-formatted but equal
+// versions (or a range) could never match. Candidates are now parsed
+// into (name, version::triple) pairs up front, and selection goes
+// through version::best_match (or a literal string comparison for a
+// bare, unprefixed `vers`, preserving the old exact-match behavior).
+//
+// read_crates walks every `view_item_use` in the crate, including
+// those inside nested modules, and resolves each one to a concrete
+// crate_meta via select_crate, keyed by the use item's node_id so
+// later passes (trans, resolve) can look a given `use` back up.
+
+import version::{constraint, triple};
+
+module creader {
+
+    type candidate = { name: str, vers: str, vers_triple: triple, metadata: @crate_meta };
+
+    // Every crate file on the search path whose #[link(name = ...)]
+    // matches `crate_name`, with its declared vers attribute parsed.
+    fn enumerate_candidates(sess: session, crate_name: str) -> [candidate] {
+        let mutable found: [candidate] = [];
+        for cmeta in metadata_on_search_path(sess, crate_name) {
+            found += [{
+                name: cmeta.name,
+                vers: cmeta.vers,
+                vers_triple: version::parse_triple(cmeta.vers),
+                metadata: cmeta
+            }];
+        }
+        ret found;
+    }
+
+    // Resolve a `use crate_name(vers = constraint_str)` to the single
+    // crate_meta it should link against. `sp` is the span of that
+    // `use` clause, so a no-match error points at the offending line
+    // instead of some ambient "current" span.
+    fn select_crate(sess: session, crate_name: str, constraint_str: str, sp: span)
+        -> @crate_meta {
+        let candidates = enumerate_candidates(sess, crate_name);
+        let c = version::parse_constraint(constraint_str);
+
+        alt c {
+            // Bare, unprefixed vers: compare the original strings
+            // directly, exactly as crate linking worked before range
+            // constraints existed.
+            version::literal(lit) {
+                for cand in candidates {
+                    if cand.vers == lit { ret cand.metadata; }
+                }
+                sess.span_fatal(sp,
+                    "no crate found for '" + crate_name +
+                    "' matching version '" + lit + "'");
+            }
+            _ {
+                let triples = vec::map(candidates, { |cand| cand.vers_triple });
+                alt version::best_match(triples, c) {
+                    some(best) {
+                        for cand in candidates {
+                            if version::cmp_triple(cand.vers_triple, best) == 0 {
+                                ret cand.metadata;
+                            }
+                        }
+                        fail "unreachable: best_match picked an unknown candidate";
+                    }
+                    none. {
+                        sess.span_fatal(sp,
+                            "no crate found for '" + crate_name +
+                            "' matching version constraint '" + constraint_str + "'");
+                    }
+                }
+            }
+        }
+    }
+
+    // Walk every `use name(vers = "...")` view-item in `crate`, nested
+    // modules included, and resolve it to the crate_meta it links
+    // against via select_crate, keyed by node_id so later passes can
+    // look a given `use` back up. resolve::linked_vers_from_crates
+    // (see src/comp/middle/resolve.rs) turns crate_meta values into
+    // the `linked_crate_vers` list resolve_crate_calls checks call
+    // paths against.
+    fn read_crates(sess: session, crate: @ast::crate) -> hashmap<ast::node_id, @crate_meta> {
+        let result = new_int_hash::<@crate_meta>();
+        read_mod_uses(sess, crate.node.module, result);
+        ret result;
+    }
+
+    // Resolves the `use` view-items of module `m` into `result`, then
+    // recurses into each nested `module` item.
+    fn read_mod_uses(sess: session, m: ast::_mod, result: hashmap<ast::node_id, @crate_meta>) {
+        for vi in m.view_items {
+            alt vi.node {
+                ast::view_item_use(crate_name, constraint_str, id) {
+                    let meta = select_crate(sess, crate_name, constraint_str, vi.span);
+                    result.insert(id, meta);
+                }
+                _ { }
+            }
+        }
+        for item in m.items {
+            alt item.node {
+                ast::item_mod(_, sub) { read_mod_uses(sess, sub, result); }
+                _ { }
+            }
+        }
+    }
+}
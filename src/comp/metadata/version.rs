@@ -0,0 +1,95 @@
+// Semantic version parsing and comparison for crate `vers` constraints.
+//
+// A `vers` string passed to `use crate(vers = "...")` is either a bare
+// literal -- matched verbatim against a candidate crate's own `vers`
+// attribute, the historical behavior -- or prefixed with `=`, `^`, or
+// `~` to request a semver-style range match against the highest
+// satisfying candidate crate metadata version. creader uses this
+// module to turn a `vers` string into a constraint and to pick the
+// best-matching candidate out of everything reachable on the search
+// path.
+
+module version {
+
+    type triple = { major: uint, minor: uint, patch: uint };
+
+    fn is_digit(c: char) -> bool { c >= '0' && c <= '9' }
+
+    fn parse_uint(s: str) -> uint {
+        let mutable n = 0u;
+        for c in str::chars(s) {
+            if !is_digit(c) { ret 0u; }
+            n = n * 10u + ((c as uint) - ('0' as uint));
+        }
+        ret n;
+    }
+
+    fn parse_triple(s: str) -> triple {
+        let parts = str::split(s, '.');
+        let major = if vec::len(parts) > 0u { parse_uint(parts[0u]) } else { 0u };
+        let minor = if vec::len(parts) > 1u { parse_uint(parts[1u]) } else { 0u };
+        let patch = if vec::len(parts) > 2u { parse_uint(parts[2u]) } else { 0u };
+        ret { major: major, minor: minor, patch: patch };
+    }
+
+    // -1, 0, 1, exactly as any other three-way compare in this tree.
+    fn cmp_triple(a: triple, b: triple) -> int {
+        if a.major != b.major { ret if a.major < b.major { -1 } else { 1 }; }
+        if a.minor != b.minor { ret if a.minor < b.minor { -1 } else { 1 }; }
+        if a.patch != b.patch { ret if a.patch < b.patch { -1 } else { 1 }; }
+        ret 0;
+    }
+
+    tag constraint {
+        literal(str);   // bare string: exact textual match, pre-range behavior
+        exact(triple);  // "=0.2.0"
+        caret(triple);  // "^0.2" / "^0.2.3": at least the given version
+                        // with the same major; when the major is 0, the
+                        // same minor instead (^0.0.3 also admits 0.0.4)
+        tilde(triple);  // "~0.2.1": patch-level changes only
+    }
+
+    fn parse_constraint(s: str) -> constraint {
+        if str::starts_with(s, "=") {
+            ret exact(parse_triple(str::slice_from(s, 1u)));
+        } else if str::starts_with(s, "^") {
+            ret caret(parse_triple(str::slice_from(s, 1u)));
+        } else if str::starts_with(s, "~") {
+            ret tilde(parse_triple(str::slice_from(s, 1u)));
+        } else {
+            ret literal(s);
+        }
+    }
+
+    fn satisfies(v: triple, c: constraint) -> bool {
+        alt c {
+            exact(t) { cmp_triple(v, t) == 0 }
+            caret(t) {
+                if t.major != 0u {
+                    v.major == t.major && cmp_triple(v, t) >= 0
+                } else {
+                    v.major == 0u && v.minor == t.minor && cmp_triple(v, t) >= 0
+                }
+            }
+            tilde(t) {
+                v.major == t.major && v.minor == t.minor && cmp_triple(v, t) >= 0
+            }
+            literal(_) { false }  // callers compare the raw strings themselves
+        }
+    }
+
+    // Pick the highest candidate triple satisfying `c`, or none if no
+    // candidate does.
+    fn best_match(candidates: [triple], c: constraint) -> option<triple> {
+        let mutable best: option<triple> = none;
+        for cand in candidates {
+            if satisfies(cand, c) {
+                best = alt best {
+                    none. { some(cand) }
+                    some(b) { if cmp_triple(cand, b) > 0 { some(cand) } else { some(b) } }
+                };
+            }
+        }
+        ret best;
+    }
+}
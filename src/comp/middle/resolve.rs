@@ -0,0 +1,178 @@
+// Name resolution passes that fall out of allowing more than one
+// source to contribute the same unqualified name into a scope -- two
+// glob imports into the same block, or two co-linked versions of the
+// same crate.
+
+module resolve {
+
+    // ---- two-phase glob resolution -------------------------------------
+    //
+    // A scope used to resolve `import m::*;` against whatever modules
+    // had already been declared above it textually, so a glob on a
+    // module declared later in the same block silently saw nothing
+    // (see src/test/run-pass/export-glob-imports-target.rs). Resolution
+    // is now two phases: gather every item (including nested `module`
+    // declarations) the enclosing block declares anywhere in its body,
+    // then expand each glob import against that complete set. Forward
+    // references, backward references, and ordinary direct declarations
+    // all resolve the same way once the gather phase has run.
+
+    type scope = { directs: hashmap<str, def>, mutable globs: [glob_import] };
+    type glob_import = { target_path: str, sp: span };
+
+    // Phase 1: walk the block's items once, without resolving
+    // anything, and record every direct declaration (fns, consts,
+    // nested modules) by name. Glob imports are recorded but not
+    // expanded yet -- that needs every sibling module to be visible
+    // first, including ones declared later in the block.
+    fn collect_scope(items: [ast::item]) -> scope {
+        let directs = new_str_hash::<def>();
+        let mutable globs: [glob_import] = [];
+        for item in items {
+            alt item.node {
+                ast::item_const(name, _) | ast::item_fn(name, _) | ast::item_mod(name, _) {
+                    directs.insert(name, def_of(item));
+                }
+                ast::item_import_glob(path, sp) {
+                    globs += [{ target_path: path, sp: sp }];
+                }
+                _ { }
+            }
+        }
+        ret { directs: directs, mutable globs: globs };
+    }
+
+    // Phase 2: now that every sibling is known, expand each glob
+    // against the complete set of items its target module declares.
+    // Two globs that export the same name into this scope, with
+    // neither shadowed by a direct declaration in this scope, are an
+    // ambiguity error rather than a silent pick of whichever glob ran
+    // last.
+    fn expand_globs(sess: session, sc: scope, resolve_mod: fn(str) -> [ (str, def) ]) {
+        // Snapshot the names this scope declared directly, *before*
+        // any glob expansion runs, so a name one glob contributes
+        // can't be mistaken for a direct declaration when a second
+        // glob contributes the same name -- that would let whichever
+        // glob is processed first win silently instead of raising the
+        // ambiguity error below.
+        let direct_names = sc.directs.keys();
+        let from_glob = new_str_hash::<str>();  // name -> owning glob's target_path
+        for g in sc.globs {
+            for (name, d) in resolve_mod(g.target_path) {
+                if vec::contains(direct_names, name) {
+                    // a direct declaration in this scope always wins
+                    // over anything globbed in, same as an ordinary
+                    // single glob import would shadow.
+                    cont;
+                }
+                alt from_glob.find(name) {
+                    some(other_path) {
+                        if other_path != g.target_path {
+                            sess.span_fatal(g.sp,
+                                "ambiguous: '" + name + "' is exported by both " +
+                                other_path + " and " + g.target_path);
+                        }
+                    }
+                    none. {
+                        from_glob.insert(name, g.target_path);
+                        sc.directs.insert(name, d);
+                    }
+                }
+            }
+        }
+    }
+
+    // ---- co-linked crate-version symbol collisions --------------------
+    //
+    // More than one version of a crate can be reachable in the same
+    // scope (see src/test/run-pass/crateresolve-ambiguity-disjoint.rs).
+    // That's fine as long as an unqualified path can only resolve to
+    // an item exported by exactly one of the co-linked versions. If
+    // two versions both export the requested symbol, an unqualified
+    // reference is genuinely ambiguous and must be rejected rather
+    // than silently bound to whichever version happened to be scanned
+    // first.
+
+    type linked_crate_vers = { crate_name: str, vers: str, exports: [str] };
+
+    // Turns the crate_meta values creader::read_crates resolved each
+    // `use` to into the flat list resolve_crate_calls checks call
+    // paths against -- the thing that actually ties read_crates'
+    // output to resolve_crate_symbol.
+    fn linked_vers_from_crates(metas: [@crate_meta]) -> [linked_crate_vers] {
+        ret vec::map(metas, { |m|
+            { crate_name: m.name, vers: m.vers, exports: m.exports }
+        });
+    }
+
+    // `path_vers` is `some(v)` when the call site annotated the path
+    // with an explicit `crate_name(vers = "v")::symbol` prefix (see
+    // front::parser::parse_path_segment), which picks that version
+    // directly and skips the ambiguity check.
+    fn resolve_crate_symbol(sess: session, linked: [linked_crate_vers], crate_name: str,
+                             symbol: str, path_vers: option<str>, sp: span)
+        -> linked_crate_vers {
+        let exporters = vec::filter(linked, { |lc|
+            lc.crate_name == crate_name && vec::contains(lc.exports, symbol)
+        });
+
+        alt path_vers {
+            some(v) {
+                for lc in exporters {
+                    if lc.vers == v { ret lc; }
+                }
+                sess.span_fatal(sp,
+                    "no linked version of '" + crate_name + "' matching '" + v +
+                    "' exports '" + symbol + "'");
+            }
+            none. {
+                if vec::len(exporters) == 0u {
+                    sess.span_fatal(sp, "unresolved name: " + crate_name + "::" + symbol);
+                } else if vec::len(exporters) == 1u {
+                    ret exporters[0u];
+                } else {
+                    sess.span_fatal(sp,
+                        "ambiguous: '" + symbol + "' is exported by both " +
+                        crate_name + "#" + exporters[0u].vers + " and " +
+                        crate_name + "#" + exporters[1u].vers);
+                }
+            }
+        }
+    }
+
+    // Checks each call expression in `exprs`: a callee path of the
+    // form `crate_name::symbol` (optionally vers-qualified, see
+    // front::parser::parse_path) is passed to resolve_crate_symbol
+    // whenever `crate_name` names a co-linked crate in `linked`. Only
+    // the outermost node of each expression is inspected; calls nested
+    // inside other expressions are not visited here.
+    //
+    // `sc` is the enclosing scope's already-collected bindings (see
+    // collect_scope/expand_globs): an ordinary local item or module
+    // always shadows a co-linked crate of the same name, exactly like
+    // any other name lookup in this resolver, so a crate-qualified
+    // reading is only attempted once the local scope has no binding
+    // for that identifier.
+    fn resolve_crate_calls(sess: session, sc: scope, linked: [linked_crate_vers],
+                            exprs: [ast::expr]) {
+        for e in exprs {
+            alt e.node {
+                ast::expr_call(callee, _) {
+                    alt callee.node {
+                        ast::expr_path(segs) if vec::len(segs) == 2u {
+                            let crate_seg: parser::path_segment = segs[0u];
+                            let symbol = segs[1u].ident;
+                            if !sc.directs.contains_key(crate_seg.ident) &&
+                               vec::any(linked, { |lc| lc.crate_name == crate_seg.ident }) {
+                                resolve_crate_symbol(sess, linked, crate_seg.ident, symbol,
+                                                      crate_seg.vers, e.span);
+                            }
+                        }
+                        _ { }
+                    }
+                }
+                _ { }
+            }
+        }
+    }
+}
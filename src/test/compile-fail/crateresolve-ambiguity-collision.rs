@@ -0,0 +1,15 @@
+// xfail-fast
+// aux-build:crateresolve7-1.rs
+// aux-build:crateresolve7-2.rs
+// error-pattern: ambiguous: 'h' is exported by both crateresolve7#0.1 and crateresolve7#0.2
+
+// crateresolve7 0.1 and 0.2 both export `h`, so an unqualified call
+// can't pick a version on its own -- see crateresolve-ambiguity-disambiguate.rs
+// for how to resolve this with an explicit vers annotation.
+
+use crateresolve7(vers = "0.1");
+use crateresolve7(vers = "0.2");
+
+fn main() {
+    assert crateresolve7::h() == 1;
+}
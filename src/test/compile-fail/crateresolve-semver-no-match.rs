@@ -0,0 +1,12 @@
+// xfail-fast
+// aux-build:crateresolve4-1.rs
+// aux-build:crateresolve4-2.rs
+// aux-build:crateresolve4-3.rs
+// error-pattern: no crate found for 'crateresolve4' matching version constraint '^0.4'
+
+// crateresolve4 is linked in versions 0.2.0, 0.2.3, 0.3.0 -- none of
+// which satisfy a caret range pinned to the 0.4 line.
+
+use crateresolve4(vers = "^0.4");
+
+fn main() { }
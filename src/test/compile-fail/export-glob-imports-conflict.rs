@@ -0,0 +1,21 @@
+// error-pattern: ambiguous: 'a' is exported by both bar and baz
+
+// Test that two sibling glob imports which export the same name are
+// rejected rather than silently picking one, regardless of the
+// textual order the globbed modules are declared in.
+
+module foo {
+    import bar::*;
+    import baz::*;
+    module bar {
+        const a : int = 10;
+    }
+    module baz {
+        const a : int = 20;
+    }
+    fn zum() {
+        let b = a;
+    }
+}
+
+fn main() { }
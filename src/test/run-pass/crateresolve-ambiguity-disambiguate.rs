@@ -0,0 +1,16 @@
+// xfail-fast
+// aux-build:crateresolve7-1.rs
+// aux-build:crateresolve7-2.rs
+
+// crateresolve7 0.1 and 0.2 both export `h` (see
+// crateresolve-ambiguity-collision.rs for the rejected unqualified
+// case); annotating the path with the intended vers picks a version
+// explicitly and resolves the ambiguity.
+
+use crateresolve7(vers = "0.1");
+use crateresolve7(vers = "0.2");
+
+fn main() {
+    assert crateresolve7(vers = "0.1")::h() == 1;
+    assert crateresolve7(vers = "0.2")::h() == 2;
+}
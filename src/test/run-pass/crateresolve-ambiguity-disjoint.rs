@@ -0,0 +1,16 @@
+// xfail-fast
+// aux-build:crateresolve6-1.rs
+// aux-build:crateresolve6-2.rs
+
+// verify that co-linking two versions of the same crate into a single
+// scope is allowed as long as their exported symbols don't collide.
+// crateresolve6 0.1 exports only `h`, 0.2 exports only `k`, so an
+// unqualified invocation of either name is unambiguous.
+
+use crateresolve6(vers = "0.1");
+use crateresolve6(vers = "0.2");
+
+fn main() {
+    assert crateresolve6::h() == 1;
+    assert crateresolve6::k() == 2;
+}
@@ -0,0 +1,33 @@
+// xfail-fast
+// aux-build:crateresolve4-1.rs
+// aux-build:crateresolve4-2.rs
+// aux-build:crateresolve4-3.rs
+
+// verify that a `vers` range constraint resolves to the highest
+// available crate matching the range, not just an exact string.
+//
+// crateresolve4 is linked in three versions: 0.2.0, 0.2.3, 0.3.0.
+
+module a {
+    // caret range: any 0.2.x build, highest is 0.2.3
+    use crateresolve4(vers = "^0.2");
+    fn f() { assert crateresolve4::f() == 23; }
+}
+
+module b {
+    // tilde range: patch-level only, 0.2.1 or later but below 0.3.0
+    use crateresolve4(vers = "~0.2.1");
+    fn f() { assert crateresolve4::f() == 23; }
+}
+
+module c {
+    // exact match still pins a single version
+    use crateresolve4(vers = "=0.3.0");
+    fn f() { assert crateresolve4::f() == 30; }
+}
+
+fn main() {
+    a::f();
+    b::f();
+    c::f();
+}
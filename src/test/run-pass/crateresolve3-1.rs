@@ -0,0 +1,3 @@
+#[link(name = "crateresolve3", vers = "0.1")];
+
+fn f() -> int { 10 }
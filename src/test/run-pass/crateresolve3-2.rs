@@ -0,0 +1,4 @@
+#[link(name = "crateresolve3", vers = "0.2")];
+
+fn f() -> int { 10 }
+fn g() -> int { 20 }
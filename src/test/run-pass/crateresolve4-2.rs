@@ -0,0 +1,3 @@
+#[link(name = "crateresolve4", vers = "0.2.3")];
+
+fn f() -> int { 23 }
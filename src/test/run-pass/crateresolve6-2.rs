@@ -0,0 +1,3 @@
+#[link(name = "crateresolve6", vers = "0.2")];
+
+fn k() -> int { 2 }
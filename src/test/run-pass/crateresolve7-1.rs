@@ -0,0 +1,3 @@
+#[link(name = "crateresolve7", vers = "0.1")];
+
+fn h() -> int { 1 }
@@ -0,0 +1,3 @@
+#[link(name = "crateresolve7", vers = "0.2")];
+
+fn h() -> int { 2 }
@@ -0,0 +1,18 @@
+// Companion to export-glob-imports-target.rs, which covers the hard
+// case of a glob import preceding the module it globs. This covers
+// the symmetric, already-trivially-working case -- the target module
+// declared *before* the glob import -- as a regression check so the
+// two-phase resolver doesn't regress ordinary backward references
+// while it's being made to handle forward ones.
+
+module foo {
+    module bar {
+        const a : int = 10;
+    }
+    import bar::*;
+    fn zum() {
+        let b = a;
+    }
+}
+
+fn main() { }